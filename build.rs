@@ -5,6 +5,36 @@ use std::env;
 use std::process::Command;
 
 fn main() {
+    println!("cargo:rerun-if-env-changed=SOLC_LIB_DIR");
+
+    // When linking against a preinstalled shared Solidity library is requested
+    // — either via the `system-solc` feature or the `SOLC_LIB_DIR` override —
+    // skip the expensive bundled CMake build entirely.
+    if cfg!(feature = "system-solc") || env::var_os("SOLC_LIB_DIR").is_some() {
+        link_system_solc();
+        return;
+    }
+
+    build_bundled_solc();
+}
+
+// Links dynamically against a Solidity shared library discovered on the system.
+fn link_system_solc() {
+    let lib_dir = env::var("SOLC_LIB_DIR").unwrap_or_else(|_| "/usr/lib".to_string());
+    println!("cargo:rustc-link-search=native={}", lib_dir);
+
+    // The combined shared library exposes the same symbols the static `solc`
+    // archive does, so a single dylib is enough.
+    println!("cargo:rustc-link-lib=dylib=solidity");
+
+    if let Some(cpp_stdlib) = get_cpp_stdlib() {
+        println!("cargo:rustc-link-lib={}", cpp_stdlib);
+    }
+}
+
+// Builds Solidity from the bundled `solidity/` source tree and links it
+// statically.
+fn build_bundled_solc() {
     let mut cmake = Config::new("solidity");
     cmake
         .define("TESTS", "OFF")
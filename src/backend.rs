@@ -0,0 +1,201 @@
+//! Pluggable compiler backends.
+//!
+//! The crate bundles one statically linked Solidity version, but a process may
+//! need to target several — contracts that pin different pragma versions cannot
+//! share a single compiler. A [`Compiler`] abstracts over where the Solidity
+//! entry points come from: either the bundled static symbols, or a shared
+//! library loaded at runtime via `dlopen`.
+
+use crate::error::SolcError;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::sync::Mutex;
+
+use crate::native::CStyleReadFileCallback;
+
+// Raw signatures of the five Solidity C entry points we bind.
+type VersionFn = unsafe extern "C" fn() -> *const c_char;
+type CompileFn =
+    unsafe extern "C" fn(*const c_char, CStyleReadFileCallback, *mut c_void) -> *mut c_char;
+type FreeFn = unsafe extern "C" fn(*mut c_char);
+type ResetFn = unsafe extern "C" fn();
+type AllocFn = unsafe extern "C" fn(u64) -> *mut c_char;
+
+// Serialize access to whichever compiler is in use; the Solidity C API keeps
+// global state, so only one compile may run at a time regardless of backend.
+lazy_static! {
+    static ref BACKEND_MUTEX: Mutex<()> = Mutex::new(());
+}
+
+// The resolved entry points for one backend.
+struct Symbols {
+    version: VersionFn,
+    compile: CompileFn,
+    free: FreeFn,
+    reset: ResetFn,
+    alloc: AllocFn,
+}
+
+/// A Solidity compiler, backed either by the bundled static library or by a
+/// shared library loaded at runtime.
+pub struct Compiler {
+    // Keeps a loaded library alive for as long as its symbols are used; `None`
+    // for the bundled backend.
+    _lib: Option<libloading::Library>,
+    symbols: Symbols,
+}
+
+impl Compiler {
+    /// Returns a compiler backed by the statically linked bundled Solidity.
+    pub fn bundled() -> Self {
+        Compiler {
+            _lib: None,
+            symbols: Symbols {
+                version: crate::native::solidity_version as VersionFn,
+                compile: crate::native::solidity_compile as CompileFn,
+                free: crate::native::solidity_free as FreeFn,
+                reset: crate::native::solidity_reset as ResetFn,
+                alloc: crate::native::solidity_alloc as AllocFn,
+            },
+        }
+    }
+
+    /// Loads a Solidity shared library from `path` and resolves its symbols.
+    pub fn from_path<P: AsRef<std::ffi::OsStr>>(path: P) -> Result<Self, SolcError> {
+        unsafe {
+            let lib = libloading::Library::new(path).map_err(SolcError::Load)?;
+            let symbols = Symbols {
+                version: *lib.get::<VersionFn>(b"solidity_version\0").map_err(SolcError::Load)?,
+                compile: *lib.get::<CompileFn>(b"solidity_compile\0").map_err(SolcError::Load)?,
+                free: *lib.get::<FreeFn>(b"solidity_free\0").map_err(SolcError::Load)?,
+                reset: *lib.get::<ResetFn>(b"solidity_reset\0").map_err(SolcError::Load)?,
+                alloc: *lib.get::<AllocFn>(b"solidity_alloc\0").map_err(SolcError::Load)?,
+            };
+            Ok(Compiler {
+                _lib: Some(lib),
+                symbols,
+            })
+        }
+    }
+
+    /// Returns this backend's compiler version string.
+    pub fn version(&self) -> String {
+        unsafe {
+            CStr::from_ptr((self.symbols.version)())
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    /// Compile using a valid JSON input and return a JSON output.
+    ///
+    /// Panics if the input cannot be encoded or the compiler lock is poisoned;
+    /// use [`try_compile`](Self::try_compile) to handle those as errors.
+    pub fn compile(&self, input: &str) -> String {
+        self.try_compile(input)
+            .expect("Could not invoke the Solidity compiler")
+    }
+
+    /// Compile using a valid JSON input, surfacing encoding and locking
+    /// failures as [`SolcError`] instead of panicking.
+    pub fn try_compile(&self, input: &str) -> Result<String, SolcError> {
+        self.compile_raw(input, None, ptr::null_mut())
+    }
+
+    /// Compile using a valid JSON input with a read callback.
+    ///
+    /// Panics if the input cannot be encoded or the compiler lock is poisoned;
+    /// use [`try_compile_with_callback`](Self::try_compile_with_callback) to
+    /// handle those as errors.
+    pub fn compile_with_callback<F>(&self, input: &str, read_callback: F) -> String
+    where
+        F: FnMut(&str, &str) -> Result<String, String>,
+    {
+        self.try_compile_with_callback(input, read_callback)
+            .expect("Could not invoke the Solidity compiler")
+    }
+
+    /// Compile using a valid JSON input with a read callback, surfacing
+    /// encoding and locking failures as [`SolcError`] instead of panicking.
+    pub fn try_compile_with_callback<F>(
+        &self,
+        input: &str,
+        read_callback: F,
+    ) -> Result<String, SolcError>
+    where
+        F: FnMut(&str, &str) -> Result<String, String>,
+    {
+        let mut context = Context {
+            callback: read_callback,
+            alloc: self.symbols.alloc,
+        };
+        let c_context = &mut context as *mut Context<F> as *mut c_void;
+        self.compile_raw(input, Some(call_callback::<F>), c_context)
+    }
+
+    fn compile_raw(
+        &self,
+        input: &str,
+        callback: CStyleReadFileCallback,
+        c_context: *mut c_void,
+    ) -> Result<String, SolcError> {
+        let input_cstr = CString::new(input)?;
+        let _lock = BACKEND_MUTEX.lock().map_err(|_| SolcError::Poisoned)?;
+
+        unsafe {
+            let ptr = (self.symbols.compile)(input_cstr.as_ptr(), callback, c_context);
+            let output = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+            (self.symbols.free)(ptr);
+            (self.symbols.reset)();
+            Ok(output)
+        }
+    }
+}
+
+// The callback closure paired with the alloc function of the backend that will
+// own the memory we hand back to Solidity.
+struct Context<F> {
+    callback: F,
+    alloc: AllocFn,
+}
+
+unsafe extern "C" fn call_callback<F>(
+    c_context: *mut c_void,
+    c_kind: *const c_char,
+    c_data: *const c_char,
+    o_contents: *mut *mut c_char,
+    o_error: *mut *mut c_char,
+) where
+    F: FnMut(&str, &str) -> Result<String, String>,
+{
+    let context = &mut *(c_context as *mut Context<F>);
+    let kind = CStr::from_ptr(c_kind).to_string_lossy().into_owned();
+    let data = CStr::from_ptr(c_data).to_string_lossy().into_owned();
+
+    match (context.callback)(&kind, &data) {
+        Ok(result) => copy_to_solidity_memory(context.alloc, &result, o_contents),
+        Err(error) => copy_to_solidity_memory(context.alloc, &error, o_error),
+    }
+}
+
+unsafe fn copy_to_solidity_memory(alloc: AllocFn, result: &str, target: *mut *mut c_char) {
+    let contents_cstr = CString::new(result).expect("Could not turn result into CString");
+    let contents_size = contents_cstr.as_bytes_with_nul().len();
+
+    // solidity_reset() in compile_raw frees the memory alloc'd here.
+    let contents_ptr = alloc(contents_size as u64);
+    ptr::copy_nonoverlapping(contents_cstr.as_ptr(), contents_ptr, contents_size);
+    (*target) = contents_ptr;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_path_missing_library_is_err() {
+        let err = Compiler::from_path("/nonexistent/libsolidity.so").unwrap_err();
+        assert!(matches!(err, SolcError::Load(_)));
+    }
+}
@@ -0,0 +1,113 @@
+//! On-disk compilation cache keyed by a content hash of the input.
+//!
+//! Compiling Solidity is expensive and the global compiler lock serializes every
+//! call, so recompiling unchanged sources is pure waste. This module derives a
+//! stable key from the normalized input JSON plus the compiler
+//! [`version`](crate::version), and
+//! stores each output as `<key>.json` under a cache directory.
+
+use crate::error::SolcError;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// In-process index of keys already known to exist on disk, so a hot key does
+// not pay a `stat` every call.
+lazy_static! {
+    static ref CACHE_INDEX: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+/// Returns the default cache directory, `<os-cache-dir>/solc-rust`.
+pub fn default_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("solc-rust")
+}
+
+/// Computes the cache key for `input` at the current compiler version.
+///
+/// The input is normalized (parsed and re-serialized with object keys sorted at
+/// every level) so that insignificant formatting and key-order differences map
+/// to the same key, then combined with [`version`](crate::version) so a
+/// compiler upgrade invalidates every entry. Keys are sorted explicitly rather
+/// than relying on `serde_json`'s default map ordering, so canonicalization
+/// holds even if the `preserve_order` feature is enabled somewhere in the tree.
+pub fn key(input: &str) -> Result<String, SolcError> {
+    let normalized: serde_json::Value = serde_json::from_str(input)?;
+    let canonical = serde_json::to_string(&sort_value(normalized))?;
+    let digest = md5::compute(format!("{}\n{}", crate::version(), canonical));
+    Ok(format!("{:x}", digest))
+}
+
+/// Recursively rebuilds `value` with every object's keys in sorted order.
+fn sort_value(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            let mut sorted = serde_json::Map::new();
+            for (k, v) in entries {
+                sorted.insert(k, sort_value(v));
+            }
+            serde_json::Value::Object(sorted)
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(sort_value).collect())
+        }
+        other => other,
+    }
+}
+
+/// Compile `input`, returning a cached output on a hit and populating the cache
+/// on a miss. Uses [`default_dir`] as the cache directory.
+pub fn compile_cached(input: &str) -> Result<String, SolcError> {
+    compile_cached_in(&default_dir(), input)
+}
+
+/// Like [`compile_cached`], but stores entries under `dir`.
+pub fn compile_cached_in(dir: &Path, input: &str) -> Result<String, SolcError> {
+    let key = key(input)?;
+    let path = dir.join(format!("{}.json", key));
+
+    // The index is only a hint that an entry was written this process: if the
+    // file was removed underneath us (cache cleared, eviction) fall through and
+    // recompile rather than erroring for the rest of the process.
+    {
+        let indexed = CACHE_INDEX.lock().map_err(|_| SolcError::Poisoned)?.contains(&key);
+        if indexed || path.exists() {
+            if let Ok(cached) = std::fs::read_to_string(&path) {
+                return Ok(cached);
+            }
+        }
+    }
+
+    let output = crate::Compiler::bundled().try_compile(input)?;
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(&path, &output)?;
+    CACHE_INDEX.lock().map_err(|_| SolcError::Poisoned)?.insert(key);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_is_independent_of_key_order_and_whitespace() {
+        let a = r#"{"language":"Solidity","sources":{"a.sol":{"content":"x"}}}"#;
+        let b = r#"{ "sources" : { "a.sol" : { "content" : "x" } }, "language" : "Solidity" }"#;
+        assert_eq!(key(a).unwrap(), key(b).unwrap());
+    }
+
+    #[test]
+    fn key_changes_with_content() {
+        let a = r#"{"sources":{"a.sol":{"content":"x"}}}"#;
+        let b = r#"{"sources":{"a.sol":{"content":"y"}}}"#;
+        assert_ne!(key(a).unwrap(), key(b).unwrap());
+    }
+
+    #[test]
+    fn key_rejects_invalid_json() {
+        assert!(matches!(key("not json"), Err(SolcError::Json(_))));
+    }
+}
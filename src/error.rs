@@ -0,0 +1,33 @@
+//! Error type for the compiler bindings.
+
+use crate::output::Diagnostic;
+use std::ffi::NulError;
+use thiserror::Error;
+
+/// Errors that can arise while driving the Solidity compiler.
+#[derive(Debug, Error)]
+pub enum SolcError {
+    /// The input could not be encoded as a C string (it contained a NUL byte).
+    #[error("input contains an interior NUL byte")]
+    Encoding(#[from] NulError),
+
+    /// The global compiler mutex was poisoned by a panic in another thread.
+    #[error("the compiler lock was poisoned")]
+    Poisoned,
+
+    /// A Standard JSON input or output could not be (de)serialized.
+    #[error("standard json (de)serialization failed")]
+    Json(#[from] serde_json::Error),
+
+    /// A cache directory or entry could not be read or written.
+    #[error("cache i/o failed")]
+    Io(#[from] std::io::Error),
+
+    /// A runtime Solidity shared library could not be loaded or bound.
+    #[error("could not load Solidity library")]
+    Load(#[source] libloading::Error),
+
+    /// The compiler ran but reported one or more fatal diagnostics.
+    #[error("compilation produced {} error(s)", .0.iter().filter(|d| d.severity == crate::output::Severity::Error).count())]
+    Compilation(Vec<Diagnostic>),
+}
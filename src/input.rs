@@ -0,0 +1,154 @@
+//! Typed model of the Solidity "Standard JSON" compiler input.
+//!
+//! This mirrors the schema accepted by `solidity_compile`, so callers can
+//! build an input with ordinary Rust values instead of hand-assembling JSON.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A complete Standard JSON input object.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StandardInput {
+    /// Source language, e.g. `"Solidity"` or `"Yul"`.
+    pub language: String,
+    /// Source units keyed by file name.
+    pub sources: BTreeMap<String, Source>,
+    #[serde(default, skip_serializing_if = "Settings::is_empty")]
+    pub settings: Settings,
+}
+
+/// A single source unit: either inline `content` or a list of `urls`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Source {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keccak256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<String>,
+}
+
+impl Source {
+    /// Builds a source from inline content.
+    pub fn from_content<S: Into<String>>(content: S) -> Self {
+        Source {
+            content: Some(content.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Compiler settings block.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Settings {
+    #[serde(default, skip_serializing_if = "Optimizer::is_default")]
+    pub optimizer: Optimizer,
+    #[serde(
+        rename = "evmVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub evm_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remappings: Vec<String>,
+    /// Pre-deployed library addresses keyed by file then library name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub libraries: BTreeMap<String, BTreeMap<String, String>>,
+    /// Requested outputs keyed by file then contract (`*` wildcards allowed).
+    #[serde(
+        rename = "outputSelection",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub output_selection: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl Settings {
+    fn is_empty(&self) -> bool {
+        Optimizer::is_default(&self.optimizer)
+            && self.evm_version.is_none()
+            && self.remappings.is_empty()
+            && self.libraries.is_empty()
+            && self.output_selection.is_empty()
+            && self.metadata.is_none()
+    }
+}
+
+/// Optimizer configuration.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Optimizer {
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runs: Option<u32>,
+}
+
+impl Optimizer {
+    fn is_default(opt: &Optimizer) -> bool {
+        !opt.enabled && opt.runs.is_none()
+    }
+}
+
+/// Metadata-related settings.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Metadata {
+    #[serde(
+        rename = "useLiteralContent",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub use_literal_content: bool,
+    #[serde(
+        rename = "bytecodeHash",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bytecode_hash: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_settings_are_omitted() {
+        let input = StandardInput {
+            language: "Solidity".to_string(),
+            sources: BTreeMap::new(),
+            settings: Settings::default(),
+        };
+        let json = serde_json::to_string(&input).unwrap();
+        assert!(!json.contains("settings"));
+        assert!(!json.contains("optimizer"));
+    }
+
+    #[test]
+    fn renamed_fields_round_trip() {
+        let mut input = StandardInput {
+            language: "Solidity".to_string(),
+            sources: BTreeMap::new(),
+            settings: Settings::default(),
+        };
+        input.sources.insert("c.sol".to_string(), Source::from_content("contract C {}"));
+        input.settings.evm_version = Some("paris".to_string());
+        input
+            .settings
+            .output_selection
+            .entry("*".to_string())
+            .or_default()
+            .insert("*".to_string(), vec!["evm.gasEstimates".to_string()]);
+
+        let json = serde_json::to_string(&input).unwrap();
+        assert!(json.contains("\"evmVersion\":\"paris\""));
+        assert!(json.contains("\"outputSelection\""));
+
+        let back: StandardInput = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.settings.evm_version.as_deref(), Some("paris"));
+        assert_eq!(
+            back.settings.output_selection["*"]["*"],
+            vec!["evm.gasEstimates".to_string()]
+        );
+    }
+}
@@ -14,11 +14,19 @@ extern crate lazy_static;
 
 mod native;
 
+pub mod backend;
+pub mod cache;
+mod error;
+pub mod input;
+pub mod output;
+pub mod resolver;
+
+pub use backend::Compiler;
+pub use error::SolcError;
+pub use input::StandardInput;
+pub use output::{Diagnostic, Severity, StandardOutput};
+
 use std::ffi::CStr;
-use std::ffi::CString;
-use std::os::raw::{c_char, c_void};
-use std::ptr;
-use std::sync::Mutex;
 
 /// Returns the compiler version string.
 pub fn version() -> String {
@@ -38,14 +46,12 @@ pub fn license() -> String {
     }
 }
 
-// Lock access to compiler
-lazy_static! {
-    static ref SOLC_MUTEX: Mutex<()> = Mutex::new(());
-}
-
 /// Compile using a valid JSON input and return a JSON output.
+///
+/// Convenience wrapper around the bundled backend; see [`Compiler`] to choose a
+/// different one at runtime.
 pub fn compile(input: &str) -> String {
-    solidity_compile(input, None, std::ptr::null_mut())
+    Compiler::bundled().compile(input)
 }
 
 /// Compile using a valid JSON input with read callback and return a JSON output.
@@ -53,66 +59,34 @@ pub fn compile_with_callback<F>(input: &str, read_callback: F) -> String
 where
     F: FnMut(&str, &str) -> Result<String, String>,
 {
-    // TODO: It should be possible to turn the box into a pointer without the into_raw-from_raw dance
-    let c_context = Box::into_raw(Box::new(read_callback));
-    let result = solidity_compile(input, Some(call_callback::<F>), c_context as *mut ());
-    unsafe { Box::from_raw(c_context) };
-    result
+    Compiler::bundled().compile_with_callback(input, read_callback)
 }
 
-fn solidity_compile(
-    input: &str,
-    callback: native::CStyleReadFileCallback,
-    c_context: *mut (),
-) -> String {
-    let input_cstr: CString =
-        CString::new(input).expect("CString failed (input contains a 0 byte?)");
-    let _lock = SOLC_MUTEX
-        .lock()
-        .expect("Could not acquire exclusive access to the compiler");
-
-    unsafe {
-        let ptr = native::solidity_compile(
-            input_cstr.as_ptr() as *const i8,
-            callback,
-            c_context as *mut _,
-        );
-        let output_cstr = CStr::from_ptr(ptr).to_string_lossy().into_owned();
-        native::solidity_free(ptr);
-        native::solidity_reset();
-        output_cstr
-    }
+/// Compile a typed [`StandardInput`] and return the typed [`StandardOutput`].
+///
+/// The input is serialized to Standard JSON, handed to the existing FFI path,
+/// and the result is deserialized. Compiler diagnostics are reported through
+/// [`StandardOutput::errors`]; use [`StandardOutput::has_error`] to check for
+/// fatal ones.
+pub fn compile_typed(input: &StandardInput) -> Result<StandardOutput, SolcError> {
+    let json = serde_json::to_string(input)?;
+    let output = Compiler::bundled().try_compile(&json)?;
+    Ok(serde_json::from_str(&output)?)
 }
 
-unsafe extern "C" fn call_callback<F>(
-    c_context: *mut c_void,
-    c_kind: *const c_char,
-    c_data: *const c_char,
-    o_contents: *mut *mut c_char,
-    o_error: *mut *mut c_char,
-) where
-    F: FnMut(&str, &str) -> Result<String, String>,
-{
-    let callback_ptr = c_context as *mut F;
-    let callback = &mut *callback_ptr;
-    let kind = CStr::from_ptr(c_kind).to_string_lossy().into_owned();
-    let data = CStr::from_ptr(c_data).to_string_lossy().into_owned();
-
-    let result: Result<String, String> = callback(&kind, &data);
-    match result {
-        Ok(result) => copy_result_to_solidity_memory(&result, o_contents),
-        Err(error) => copy_result_to_solidity_memory(&error, o_error),
+/// Compile a Standard JSON input and return the typed [`StandardOutput`],
+/// failing if the compiler reported any error-severity diagnostic.
+///
+/// Unlike [`compile`], this surfaces FFI, locking and JSON failures as a
+/// [`SolcError`] rather than panicking, and collects fatal diagnostics into
+/// [`SolcError::Compilation`].
+pub fn compile_checked(input: &str) -> Result<StandardOutput, SolcError> {
+    let output = Compiler::bundled().try_compile(input)?;
+    let parsed: StandardOutput = serde_json::from_str(&output)?;
+    if parsed.has_error() {
+        return Err(SolcError::Compilation(parsed.errors));
     }
-}
-
-unsafe fn copy_result_to_solidity_memory(result: &str, target: *mut *mut c_char) {
-    let contents_cstr: CString = CString::new(result).expect("Could not turn result into CString");
-    let contents_size = contents_cstr.as_bytes_with_nul().len();
-
-    // The solidity_reset() call in solidity_compile takes care of freeing the memory alloc'd here
-    let contents_ptr: *mut c_char = native::solidity_alloc(contents_size as u64);
-    ptr::copy_nonoverlapping(contents_cstr.as_ptr(), contents_ptr, contents_size);
-    (*target) = contents_ptr;
+    Ok(parsed)
 }
 
 #[cfg(test)]
@@ -0,0 +1,166 @@
+//! Typed model of the Solidity "Standard JSON" compiler output.
+//!
+//! The compiler always answers with the same top-level shape; this module
+//! lets callers inspect diagnostics and artifacts through typed fields rather
+//! than by scanning the raw JSON string.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A complete Standard JSON output object.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StandardOutput {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<Diagnostic>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sources: BTreeMap<String, SourceOutput>,
+    /// Compiled contracts keyed by file name then contract name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub contracts: BTreeMap<String, BTreeMap<String, Contract>>,
+}
+
+impl StandardOutput {
+    /// Returns true if any diagnostic has error severity.
+    pub fn has_error(&self) -> bool {
+        self.errors.iter().any(|d| d.severity == Severity::Error)
+    }
+}
+
+/// Severity of a single diagnostic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+/// A single compiler diagnostic.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub component: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    #[serde(
+        rename = "formattedMessage",
+        default,
+        skip_serializing_if = "String::is_empty"
+    )]
+    pub formatted_message: String,
+    #[serde(
+        rename = "sourceLocation",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub source_location: Option<SourceLocation>,
+}
+
+/// Location of a diagnostic within a source file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SourceLocation {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub file: String,
+    pub start: i64,
+    pub end: i64,
+}
+
+/// Per-source-unit output (identifier, AST, …).
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SourceOutput {
+    pub id: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ast: Option<serde_json::Value>,
+}
+
+/// A single compiled contract.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Contract {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abi: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    #[serde(default, skip_serializing_if = "Evm::is_empty")]
+    pub evm: Evm,
+}
+
+/// The `evm` artifact block.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Evm {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytecode: Option<Bytecode>,
+    #[serde(
+        rename = "deployedBytecode",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub deployed_bytecode: Option<Bytecode>,
+    #[serde(
+        rename = "gasEstimates",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub gas_estimates: Option<serde_json::Value>,
+}
+
+impl Evm {
+    fn is_empty(&self) -> bool {
+        self.bytecode.is_none() && self.deployed_bytecode.is_none() && self.gas_estimates.is_none()
+    }
+}
+
+/// Bytecode artifact.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Bytecode {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub object: String,
+    #[serde(rename = "opcodes", default, skip_serializing_if = "String::is_empty")]
+    pub opcodes: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn has_error_detects_error_severity() {
+        let json = r#"{
+            "errors": [
+                { "severity": "warning", "type": "Warning", "formattedMessage": "w" },
+                { "severity": "error", "type": "TypeError", "formattedMessage": "e" }
+            ]
+        }"#;
+        let output: StandardOutput = serde_json::from_str(json).unwrap();
+        assert_eq!(output.errors.len(), 2);
+        assert_eq!(output.errors[0].severity, Severity::Warning);
+        assert!(output.has_error());
+    }
+
+    #[test]
+    fn warnings_only_is_not_an_error() {
+        let json = r#"{ "errors": [ { "severity": "warning", "type": "Warning" } ] }"#;
+        let output: StandardOutput = serde_json::from_str(json).unwrap();
+        assert!(!output.has_error());
+    }
+
+    #[test]
+    fn gas_estimates_rename_round_trips() {
+        let json = r#"{
+            "contracts": {
+                "c.sol": {
+                    "C": { "evm": { "gasEstimates": { "creation": { "totalCost": "1" } } } }
+                }
+            }
+        }"#;
+        let output: StandardOutput = serde_json::from_str(json).unwrap();
+        let evm = &output.contracts["c.sol"]["C"].evm;
+        assert!(evm.gas_estimates.is_some());
+
+        // The camelCase rename survives re-serialization.
+        let reemitted = serde_json::to_string(&output).unwrap();
+        assert!(reemitted.contains("gasEstimates"));
+    }
+}
@@ -0,0 +1,187 @@
+//! Filesystem import resolver built on the read callback.
+//!
+//! Solidity emits a `("source", path)` read request for every import it cannot
+//! satisfy from the inline sources. [`Resolver`] turns those requests into file
+//! reads: it applies `prefix=target` remappings longest-prefix-first, then looks
+//! the result up under a set of root directories.
+
+use std::path::{Path, PathBuf};
+
+/// Resolves `import "..."` requests against a set of roots and remappings.
+pub struct Resolver {
+    roots: Vec<PathBuf>,
+    remappings: Vec<(String, String)>,
+    recursive: bool,
+}
+
+impl Resolver {
+    /// Builds a resolver from root directories and `prefix=target` remappings.
+    ///
+    /// Malformed remappings (those without a `=`) are ignored.
+    pub fn new<R, M>(roots: R, remappings: M) -> Self
+    where
+        R: IntoIterator,
+        R::Item: Into<PathBuf>,
+        M: IntoIterator,
+        M::Item: AsRef<str>,
+    {
+        let mut remappings: Vec<(String, String)> = remappings
+            .into_iter()
+            .filter_map(|m| {
+                m.as_ref()
+                    .split_once('=')
+                    .map(|(p, t)| (p.to_string(), t.to_string()))
+            })
+            .collect();
+        // Longest prefix first, so the most specific remapping wins.
+        remappings.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+
+        Resolver {
+            roots: roots.into_iter().map(Into::into).collect(),
+            remappings,
+            recursive: false,
+        }
+    }
+
+    /// Enables recursive search of the root directories.
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Resolves a single callback request, matching the callback signature of
+    /// [`compile_with_callback`](crate::compile_with_callback).
+    pub fn resolve(&self, kind: &str, data: &str) -> Result<String, String> {
+        if kind != "source" {
+            return Err(format!("unsupported callback kind: {}", kind));
+        }
+
+        let remapped = self.apply_remappings(data);
+        let candidate = Path::new(&remapped);
+        if candidate.is_absolute() {
+            if let Some(contents) = read_candidate(candidate)? {
+                return Ok(contents);
+            }
+        }
+
+        for root in &self.roots {
+            let direct = root.join(&remapped);
+            if let Some(contents) = read_candidate(&direct)? {
+                return Ok(contents);
+            }
+            if self.recursive {
+                if let Some(found) = search_dir(root, &remapped)? {
+                    if let Some(contents) = read_candidate(&found)? {
+                        return Ok(contents);
+                    }
+                }
+            }
+        }
+
+        Err(format!("file not found: {}", data))
+    }
+
+    fn apply_remappings(&self, path: &str) -> String {
+        for (prefix, target) in &self.remappings {
+            if let Some(rest) = path.strip_prefix(prefix) {
+                return format!("{}{}", target, rest);
+            }
+        }
+        path.to_string()
+    }
+}
+
+/// Reads `path`, distinguishing "not found" (`Ok(None)`, so the caller keeps
+/// searching) from a genuine I/O failure on an existing file (`Err`).
+fn read_candidate(path: &Path) -> Result<Option<String>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+    }
+}
+
+/// Walks `dir` looking for a file whose path ends with `suffix`.
+///
+/// Entries are visited in sorted order, so an import that two files under the
+/// roots could satisfy resolves to the same one every time. Symlinked
+/// directories are not descended into, so a link back to an ancestor (common in
+/// `node_modules`/`lib` trees) cannot loop forever. I/O errors are surfaced
+/// rather than collapsed into "not found".
+fn search_dir(dir: &Path, suffix: &str) -> Result<Option<PathBuf>, String> {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)
+        .and_then(|rd| rd.map(|e| e.map(|e| e.path())).collect::<Result<_, _>>())
+        .map_err(|e| format!("could not read {}: {}", dir.display(), e))?;
+    entries.sort();
+
+    for path in entries {
+        let meta = path
+            .symlink_metadata()
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+        if meta.file_type().is_symlink() {
+            // Don't follow symlinks: a link back to an ancestor would loop.
+            continue;
+        }
+        if meta.is_dir() {
+            if let Some(found) = search_dir(&path, suffix)? {
+                return Ok(Some(found));
+            }
+        } else if path.ends_with(suffix) {
+            return Ok(Some(path));
+        }
+    }
+    Ok(None)
+}
+
+/// Compile `input`, resolving imports against `roots` and `remappings`.
+///
+/// This wires a [`Resolver`] into
+/// [`try_compile_with_callback`](crate::Compiler::try_compile_with_callback), so
+/// callers get node-module-style import resolution without writing the callback
+/// themselves. A resolution failure surfaces as a diagnostic in the returned
+/// output; encoding or locking failures surface as [`SolcError`].
+///
+/// Imports are resolved by joining the remapped path onto each root; enable
+/// [`Resolver::recursive`] explicitly if you also want a directory walk. The
+/// wrapper does not, since a walk can resolve the same import to different files
+/// depending on the layout.
+pub fn compile_with_paths<R, M>(
+    input: &str,
+    roots: R,
+    remappings: M,
+) -> Result<String, crate::SolcError>
+where
+    R: IntoIterator,
+    R::Item: Into<PathBuf>,
+    M: IntoIterator,
+    M::Item: AsRef<str>,
+{
+    let resolver = Resolver::new(roots, remappings);
+    crate::Compiler::bundled()
+        .try_compile_with_callback(input, |kind: &str, data: &str| resolver.resolve(kind, data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remappings_apply_longest_prefix_first() {
+        let resolver = Resolver::new(Vec::<PathBuf>::new(), ["@oz/=lib/oz/", "@oz/token/=t/"]);
+        assert_eq!(resolver.apply_remappings("@oz/token/ERC20.sol"), "t/ERC20.sol");
+        assert_eq!(resolver.apply_remappings("@oz/math/Safe.sol"), "lib/oz/math/Safe.sol");
+    }
+
+    #[test]
+    fn non_source_kind_is_rejected() {
+        let resolver = Resolver::new(Vec::<PathBuf>::new(), Vec::<String>::new());
+        assert!(resolver.resolve("license", "whatever").is_err());
+    }
+
+    #[test]
+    fn missing_file_reports_not_found() {
+        let resolver = Resolver::new(vec![PathBuf::from("/nonexistent-root")], Vec::<String>::new());
+        let err = resolver.resolve("source", "d.sol").unwrap_err();
+        assert!(err.starts_with("file not found"));
+    }
+}